@@ -1,6 +1,6 @@
 use std::env;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader, Write};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
 use std::process;
 
 #[derive(Clone, Copy)]
@@ -27,14 +27,17 @@ Concatenate FILE(s) to standard output.
 With no FILE, or when FILE is -, read standard input.
 
 Options:
-    -b              number nonempty output lines, overrides -n
-    -E, -e          display $ at end of each line
-    -n              number all output lines
-    -s              suppress repeated empty output lines
-    -t, -T          display TAB characters as ^I
-    -u              (ignored) for compatibility with GNU cat
-    -v              use ^ and M- notation, except for LFD and TAB
-    -h, -?, --help  display this help and exit
+    -A, --show-all           equivalent to -vET
+    -b, --number-nonblank    number nonempty output lines, overrides -n
+    -e                       equivalent to -vE
+    -E, --show-ends          display $ at end of each line
+    -n, --number             number all output lines
+    -s, --squeeze-blank      suppress repeated empty output lines
+    -t                       equivalent to -vT
+    -T, --show-tabs          display TAB characters as ^I
+    -u                       (ignored) for compatibility with GNU cat
+    -v, --show-nonprinting   use ^ and M- notation, except for LFD and TAB
+    -h, -?, --help           display this help and exit
 
 Examples:
     rocat f - g      Output f's contents, then standard input, then g's contents.
@@ -44,54 +47,180 @@ Please report bugs to: https://github.com/rwoliver2/rocat/issues");
     process::exit(0);
 }
 
+// Accumulates the flags seen while walking argv, before they are folded
+// into an `Options`. Kept separate from `Options` because a single short
+// flag (`-A`, `-e`, `-t`) can toggle several of these fields at once.
+#[derive(Default)]
+struct RawFlags {
+    number_all: bool,
+    number_nonblank: bool,
+    show_ends: bool,
+    squeeze_blank: bool,
+    show_tabs: bool,
+    show_nonprinting: bool,
+    unbuffered: bool,
+}
+
+impl RawFlags {
+    fn into_options(self) -> Options {
+        Options {
+            // -b takes precedence over -n to match GNU's behavior
+            numbering_mode: if self.number_nonblank {
+                NumberingMode::NonBlank
+            } else if self.number_all {
+                NumberingMode::All
+            } else {
+                NumberingMode::None
+            },
+            show_ends: self.show_ends,
+            squeeze_blank: self.squeeze_blank,
+            show_tabs: self.show_tabs,
+            show_nonprinting: self.show_nonprinting,
+            unbuffered: self.unbuffered,
+        }
+    }
+}
+
+// Applies a single short-option letter to `flags`, expanding the composite
+// aliases (-A = -vET, -e = -vE, -t = -vT) exactly as GNU cat documents them.
+// Returns false if `c` is not a recognized short option.
+fn apply_short_flag(c: char, flags: &mut RawFlags) -> bool {
+    match c {
+        'n' => flags.number_all = true,
+        'b' => flags.number_nonblank = true,
+        'E' => flags.show_ends = true,
+        's' => flags.squeeze_blank = true,
+        'T' => flags.show_tabs = true,
+        'v' => flags.show_nonprinting = true,
+        'u' => flags.unbuffered = true,
+        'e' => {
+            flags.show_nonprinting = true;
+            flags.show_ends = true;
+        }
+        't' => {
+            flags.show_nonprinting = true;
+            flags.show_tabs = true;
+        }
+        'A' => {
+            flags.show_nonprinting = true;
+            flags.show_ends = true;
+            flags.show_tabs = true;
+        }
+        _ => return false,
+    }
+    true
+}
+
+// Applies a bundled single-dash cluster, given without its leading dash
+// (e.g. "vET" for the argument "-vET"), to `flags`. Returns
+// false, leaving `flags` untouched, if any character in the cluster is not
+// a recognized short option.
+fn apply_short_cluster(cluster: &str, flags: &mut RawFlags) -> bool {
+    let mut staged = RawFlags::default();
+    for c in cluster.chars() {
+        if !apply_short_flag(c, &mut staged) {
+            return false;
+        }
+    }
+    flags.number_all |= staged.number_all;
+    flags.number_nonblank |= staged.number_nonblank;
+    flags.show_ends |= staged.show_ends;
+    flags.squeeze_blank |= staged.squeeze_blank;
+    flags.show_tabs |= staged.show_tabs;
+    flags.show_nonprinting |= staged.show_nonprinting;
+    flags.unbuffered |= staged.unbuffered;
+    true
+}
+
+// Applies a GNU long option (e.g. "--show-ends") to `flags`. Returns false
+// if `name` is not a recognized long option.
+fn apply_long_flag(name: &str, flags: &mut RawFlags) -> bool {
+    match name {
+        "--number" => flags.number_all = true,
+        "--number-nonblank" => flags.number_nonblank = true,
+        "--show-ends" => flags.show_ends = true,
+        "--squeeze-blank" => flags.squeeze_blank = true,
+        "--show-tabs" => flags.show_tabs = true,
+        "--show-nonprinting" => flags.show_nonprinting = true,
+        "--show-all" => {
+            flags.show_nonprinting = true;
+            flags.show_ends = true;
+            flags.show_tabs = true;
+        }
+        _ => return false,
+    }
+    true
+}
+
+// Parses argv (excluding argv[0]) into `Options` plus the list of file
+// operands, in order. Bundled short clusters ("-vET"), long options
+// ("--show-ends"), and "--" as an end-of-flags marker are all honored so
+// that filenames starting with "-" can still be passed after "--".
+fn parse_args(args: &[String]) -> (Options, Vec<String>) {
+    let mut flags = RawFlags::default();
+    let mut files = Vec::new();
+    let mut end_of_flags = false;
+
+    for arg in args {
+        if end_of_flags {
+            files.push(arg.clone());
+        } else if arg == "--" {
+            end_of_flags = true;
+        } else if arg == "-" {
+            // A lone "-" is the stdin placeholder, never a flag.
+            files.push(arg.clone());
+        } else if let Some(name) = arg.strip_prefix("--") {
+            if !apply_long_flag(&format!("--{name}"), &mut flags) {
+                files.push(arg.clone());
+            }
+        } else if let Some(cluster) = arg.strip_prefix('-') {
+            if cluster.is_empty() || !apply_short_cluster(cluster, &mut flags) {
+                files.push(arg.clone());
+            }
+        } else {
+            files.push(arg.clone());
+        }
+    }
+
+    (flags.into_options(), files)
+}
+
 fn main() -> io::Result<()> {
     let args: Vec<String> = env::args().collect();
-    
+
     // Check for help flags before anything else
     if args.len() > 1 && (args[1] == "-h" || args[1] == "--help" || args[1] == "-?") {
         print_help();
     }
-    
-    // Parse supplied options
-    let options = Options {
-        // -n takes precedence over -b to match GNU's behavior
-        numbering_mode: if args.iter().any(|arg| arg == "-n") {
-            NumberingMode::All
-        } else if args.iter().any(|arg| arg == "-b") {
-            NumberingMode::NonBlank
-        } else {
-            NumberingMode::None
-        },
-        show_ends: args.iter().any(|arg| arg == "-e" || arg == "-E"),
-        squeeze_blank: args.iter().any(|arg| arg == "-s"),
-        show_tabs: args.iter().any(|arg| arg == "-t" || arg == "-T"),
-        show_nonprinting: args.iter().any(|arg| arg == "-v"),
-        unbuffered: args.iter().any(|arg| arg == "-u"),
-    };
-    
-    // If no files are specified (excluding the flags), read from stdin
-    let has_files = args.iter().skip(1).any(|arg| !is_flag(arg));
-    if !has_files {
+
+    let (options, files) = parse_args(&args[1..]);
+
+    // If no files are specified, read from stdin
+    if files.is_empty() {
         return cat_stdin(&options);
     }
 
-    // Process file(s) specified in the arguments, skipping flags
-    let files: Vec<&String> = args[1..].iter()
-        .filter(|arg| !is_flag(arg))
-        .collect();
+    let mut had_error = false;
+    for file_path in &files {
+        // "-" anywhere in the file list means standard input, so
+        // `rocat f - g` interleaves stdin with real files.
+        let result = if file_path == "-" {
+            cat_stdin(&options)
+        } else {
+            cat_file(file_path, &options)
+        };
 
-    for file_path in files {
-        if let Err(err) = cat_file(file_path, &options) {
+        if let Err(err) = result {
             eprintln!("Error reading {}: {}", file_path, err);
+            had_error = true;
         }
     }
 
-    Ok(())
-}
+    if had_error {
+        process::exit(1);
+    }
 
-fn is_flag(arg: &str) -> bool {
-    matches!(arg, "-n" | "-b" | "-e" | "-E" | "-s" | "-t" | "-T" | "-u" | "-v" | 
-                  "-h" | "--help" | "-?")
+    Ok(())
 }
 
 fn cat_stdin(options: &Options) -> io::Result<()> {
@@ -106,24 +235,67 @@ fn cat_file(file_path: &str, options: &Options) -> io::Result<()> {
     print_lines(reader, options)
 }
 
+// Matches the uutils `head` implementation's read buffer size.
+const BUF_SIZE: usize = 64 * 1024;
+
 fn print_lines<R: BufRead>(reader: R, options: &Options) -> io::Result<()> {
     let stdout = io::stdout();
-    let mut writer = stdout.lock();
-    print_lines_to_writer(reader, options, &mut writer)
+    let mut writer = BufWriter::with_capacity(BUF_SIZE, stdout.lock());
+    if needs_transform(options) {
+        print_lines_to_writer(reader, options, &mut writer)?;
+    } else {
+        copy_raw(reader, &mut writer)?;
+    }
+    writer.flush()
+}
+
+// True if any option requires inspecting/rewriting line content. When none
+// do, input bytes can be streamed straight through untouched.
+fn needs_transform(options: &Options) -> bool {
+    !matches!(options.numbering_mode, NumberingMode::None)
+        || options.show_ends
+        || options.squeeze_blank
+        || options.show_tabs
+        || options.show_nonprinting
+}
+
+// Plain byte-for-byte copy, used when no transforming option is set. Avoids
+// `reader.lines()`/UTF-8 decoding entirely so arbitrary binary data and
+// large files pass through untouched and fast.
+fn copy_raw<R: Read, W: Write>(mut reader: R, writer: &mut W) -> io::Result<()> {
+    let mut buf = [0u8; BUF_SIZE];
+    loop {
+        let bytes_read = reader.read(&mut buf)?;
+        if bytes_read == 0 {
+            break;
+        }
+        writer.write_all(&buf[..bytes_read])?;
+    }
+    Ok(())
 }
 
 // print_lines function that accepts a generic writer
 fn print_lines_to_writer<R: BufRead, W: Write>(
-    reader: R,
+    mut reader: R,
     options: &Options,
     writer: &mut W,
 ) -> io::Result<()> {
     let mut line_number = 1;
     let mut last_was_blank = false;
+    let mut line = Vec::new();
 
-    for line in reader.lines() {
-        let line = line?;
-        let is_blank = line.trim().is_empty();
+    loop {
+        line.clear();
+        let bytes_read = reader.read_until(b'\n', &mut line)?;
+        if bytes_read == 0 {
+            break;
+        }
+
+        let has_newline = line.last() == Some(&b'\n');
+        if has_newline {
+            line.pop();
+        }
+        let is_blank = line.is_empty();
 
         if options.squeeze_blank && is_blank && last_was_blank {
             continue;
@@ -144,28 +316,46 @@ fn print_lines_to_writer<R: BufRead, W: Write>(
             NumberingMode::None => {}
         }
 
-        let mut output = String::new();
-        for c in line.chars() {
-            match c {
-                '\t' if options.show_tabs => output.push_str("^I"),
-                c if options.show_nonprinting && !c.is_ascii_graphic() && !c.is_ascii_whitespace() => {
-                    output.push('^');
-                    output.push((c as u8 + 64) as char);
-                }
-                c => output.push(c),
+        let mut output = Vec::with_capacity(line.len());
+        for &b in &line {
+            match b {
+                b'\t' if options.show_tabs => output.extend_from_slice(b"^I"),
+                b'\t' if options.show_nonprinting => output.push(b),
+                b if options.show_nonprinting => output.extend(encode_nonprinting(b)),
+                b => output.push(b),
             }
         }
 
-        write!(writer, "{}", output)?;
-        
+        writer.write_all(&output)?;
+
         if options.show_ends {
             write!(writer, "$")?;
         }
-        writeln!(writer)?;
+        if has_newline {
+            writer.write_all(b"\n")?;
+        }
     }
     Ok(())
 }
 
+// Renders a single byte using GNU cat's `-v` meta/caret notation:
+// bytes >= 128 get an "M-" prefix and are then encoded as their low 7-bit
+// counterpart, 127 (DEL) becomes "^?", and control bytes < 32 become "^"
+// followed by the byte shifted into the printable range.
+fn encode_nonprinting(b: u8) -> Vec<u8> {
+    if b >= 128 {
+        let mut encoded = vec![b'M', b'-'];
+        encoded.extend(encode_nonprinting(b - 128));
+        encoded
+    } else if b == 127 {
+        vec![b'^', b'?']
+    } else if b < 32 {
+        vec![b'^', b + 64]
+    } else {
+        vec![b]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,6 +373,18 @@ mod tests {
         Ok(String::from_utf8(output).unwrap())
     }
 
+    // Helper function to process raw bytes, for cases a &str can't represent
+    // (e.g. byte 0xFF, which is not valid UTF-8 on its own)
+    fn process_bytes(input: &[u8], options: &Options) -> io::Result<Vec<u8>> {
+        let cursor = Cursor::new(input);
+        let mut output = Vec::new();
+        {
+            let mut custom_writer = Cursor::new(&mut output);
+            print_lines_to_writer(cursor, options, &mut custom_writer)?;
+        }
+        Ok(output)
+    }
+
     fn default_options() -> Options {
         Options {
             numbering_mode: NumberingMode::None,
@@ -194,9 +396,66 @@ mod tests {
         }
     }
 
+    fn sargs(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_bundled_short_flags() {
+        let (options, files) = parse_args(&sargs(&["-nE", "file"]));
+        assert!(matches!(options.numbering_mode, NumberingMode::All));
+        assert!(options.show_ends);
+        assert_eq!(files, vec!["file".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_args_long_options() {
+        let (options, files) = parse_args(&sargs(&[
+            "--number-nonblank",
+            "--show-tabs",
+            "file",
+        ]));
+        assert!(matches!(options.numbering_mode, NumberingMode::NonBlank));
+        assert!(options.show_tabs);
+        assert_eq!(files, vec!["file".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_args_composite_aliases() {
+        let (options, _) = parse_args(&sargs(&["-A"]));
+        assert!(options.show_nonprinting);
+        assert!(options.show_ends);
+        assert!(options.show_tabs);
+
+        let (options, _) = parse_args(&sargs(&["-e"]));
+        assert!(options.show_nonprinting);
+        assert!(options.show_ends);
+        assert!(!options.show_tabs);
+
+        let (options, _) = parse_args(&sargs(&["-t"]));
+        assert!(options.show_nonprinting);
+        assert!(options.show_tabs);
+        assert!(!options.show_ends);
+    }
+
+    #[test]
+    fn test_parse_args_end_of_flags_separator() {
+        let (_, files) = parse_args(&sargs(&["--", "-n", "-file"]));
+        assert_eq!(files, vec!["-n".to_string(), "-file".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_args_lone_dash_is_a_file() {
+        let (_, files) = parse_args(&sargs(&["f", "-", "g"]));
+        assert_eq!(
+            files,
+            vec!["f".to_string(), "-".to_string(), "g".to_string()]
+        );
+    }
+
     #[test]
     fn test_basic_output() -> io::Result<()> {
-        let input = "Hello\nWorld";
+        let input = "Hello\nWorld\n";
         let options = default_options();
         let output = process_string(input, &options)?;
         assert_eq!(output, "Hello\nWorld\n");
@@ -204,8 +463,17 @@ mod tests {
     }
 
     #[test]
-    fn test_number_all_lines() -> io::Result<()> {
+    fn test_preserves_missing_trailing_newline() -> io::Result<()> {
         let input = "Hello\nWorld";
+        let options = default_options();
+        let output = process_string(input, &options)?;
+        assert_eq!(output, "Hello\nWorld");
+        Ok(())
+    }
+
+    #[test]
+    fn test_number_all_lines() -> io::Result<()> {
+        let input = "Hello\nWorld\n";
         let mut options = default_options();
         options.numbering_mode = NumberingMode::All;
         let output = process_string(input, &options)?;
@@ -215,7 +483,7 @@ mod tests {
 
     #[test]
     fn test_number_nonblank_lines() -> io::Result<()> {
-        let input = "Hello\n\nWorld";
+        let input = "Hello\n\nWorld\n";
         let mut options = default_options();
         options.numbering_mode = NumberingMode::NonBlank;
         let output = process_string(input, &options)?;
@@ -225,7 +493,7 @@ mod tests {
 
     #[test]
     fn test_show_ends() -> io::Result<()> {
-        let input = "Hello\nWorld";
+        let input = "Hello\nWorld\n";
         let mut options = default_options();
         options.show_ends = true;
         let output = process_string(input, &options)?;
@@ -235,7 +503,7 @@ mod tests {
 
     #[test]
     fn test_squeeze_blank() -> io::Result<()> {
-        let input = "Hello\n\n\n\nWorld";
+        let input = "Hello\n\n\n\nWorld\n";
         let mut options = default_options();
         options.squeeze_blank = true;
         let output = process_string(input, &options)?;
@@ -245,7 +513,7 @@ mod tests {
 
     #[test]
     fn test_show_tabs() -> io::Result<()> {
-        let input = "Hello\tWorld";
+        let input = "Hello\tWorld\n";
         let mut options = default_options();
         options.show_tabs = true;
         let output = process_string(input, &options)?;
@@ -255,7 +523,7 @@ mod tests {
 
     #[test]
     fn test_show_nonprinting() -> io::Result<()> {
-        let input = "Hello\u{0001}World";
+        let input = "Hello\u{0001}World\n";
         let mut options = default_options();
         options.show_nonprinting = true;
         let output = process_string(input, &options)?;
@@ -263,9 +531,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_show_nonprinting_del_and_high_bytes() -> io::Result<()> {
+        let input = b"Hello\x7F\xFFWorld\n";
+        let mut options = default_options();
+        options.show_nonprinting = true;
+        let output = process_bytes(input, &options)?;
+        assert_eq!(output, b"Hello^?M-^?World\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_show_nonprinting_leaves_tabs_alone() -> io::Result<()> {
+        let input = "Hello\tWorld\n";
+        let mut options = default_options();
+        options.show_nonprinting = true;
+        let output = process_string(input, &options)?;
+        assert_eq!(output, "Hello\tWorld\n");
+        Ok(())
+    }
+
     #[test]
     fn test_multiple_options() -> io::Result<()> {
-        let input = "Hello\n\n\tWorld";
+        let input = "Hello\n\n\tWorld\n";
         let mut options = default_options();
         options.show_ends = true;
         options.show_tabs = true;
@@ -274,4 +562,22 @@ mod tests {
         assert_eq!(output, "Hello$\n$\n^IWorld$\n");
         Ok(())
     }
+
+    #[test]
+    fn test_copy_raw_passes_bytes_through_unchanged() -> io::Result<()> {
+        let input: &[u8] = b"arbitrary \xFF\x00binary data\n";
+        let mut output = Vec::new();
+        copy_raw(input, &mut output)?;
+        assert_eq!(output, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_needs_transform() {
+        assert!(!needs_transform(&default_options()));
+
+        let mut options = default_options();
+        options.show_ends = true;
+        assert!(needs_transform(&options));
+    }
 }
\ No newline at end of file